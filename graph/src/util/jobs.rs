@@ -0,0 +1,116 @@
+//! A tiny in-process scheduler for periodic maintenance jobs.
+//!
+//! Jobs are registered with either a fixed interval or a cron expression;
+//! `Runner::tick` (driven by the node's main loop) runs any job whose next
+//! scheduled time has passed and reschedules it.
+
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use cron::Schedule as CronSchedule;
+use slog::Logger;
+
+/// Something that can be run on a schedule.
+pub trait Job: Send + Sync {
+    fn name(&self) -> &str;
+    fn run(&self, logger: &Logger);
+}
+
+/// How often a registered job should fire.
+pub enum Schedule {
+    /// Run every `Duration`, starting `Duration` after registration.
+    Every(Duration),
+    /// Run according to a 5-field cron expression, e.g. `"*/5 * * * *"`.
+    Cron(String),
+}
+
+struct Registration {
+    job: Box<dyn Job>,
+    schedule: Schedule,
+    cron: Option<CronSchedule>,
+    next_run: Instant,
+}
+
+impl Registration {
+    fn new(job: Box<dyn Job>, schedule: Schedule) -> Self {
+        let cron = match &schedule {
+            Schedule::Every(_) => None,
+            Schedule::Cron(expr) => {
+                // The `cron` crate expects a leading seconds field; a
+                // 5-field expression fires at second 0 of each match.
+                let full = if expr.split_whitespace().count() == 5 {
+                    format!("0 {}", expr)
+                } else {
+                    expr.clone()
+                };
+                Some(
+                    CronSchedule::from_str(&full)
+                        .unwrap_or_else(|e| panic!("invalid cron expression `{}`: {}", expr, e)),
+                )
+            }
+        };
+        let next_run = Self::compute_next_run(&schedule, cron.as_ref());
+        Registration {
+            job,
+            schedule,
+            cron,
+            next_run,
+        }
+    }
+
+    fn compute_next_run(schedule: &Schedule, cron: Option<&CronSchedule>) -> Instant {
+        match schedule {
+            Schedule::Every(interval) => Instant::now() + *interval,
+            Schedule::Cron(_) => {
+                let cron = cron.expect("a Cron schedule always has a parsed expression");
+                let until_next = cron
+                    .upcoming(Utc)
+                    .next()
+                    .and_then(|fire_at| (fire_at - Utc::now()).to_std().ok())
+                    .unwrap_or(Duration::from_secs(0));
+                Instant::now() + until_next
+            }
+        }
+    }
+
+    fn reschedule(&mut self) {
+        self.next_run = Self::compute_next_run(&self.schedule, self.cron.as_ref());
+    }
+}
+
+/// Runs registered jobs on their configured schedule. Owns the jobs and is
+/// driven by repeatedly calling `tick`, typically from a loop on its own
+/// thread with a short sleep in between.
+pub struct Runner {
+    logger: Logger,
+    jobs: Vec<Registration>,
+}
+
+impl Runner {
+    pub fn new(logger: Logger) -> Self {
+        Runner {
+            logger,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Register `job` to run on `schedule`. `Every(duration)` preserves the
+    /// old fixed-interval behavior; `Cron(expr)` fires at the times
+    /// described by a 5-field cron expression instead.
+    pub fn register(&mut self, job: Box<dyn Job>, schedule: Schedule) {
+        self.jobs.push(Registration::new(job, schedule));
+    }
+
+    /// Run any job whose scheduled time has passed, and reschedule it.
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        for reg in self.jobs.iter_mut() {
+            if now < reg.next_run {
+                continue;
+            }
+            reg.job.run(&self.logger);
+            reg.reschedule();
+        }
+    }
+}
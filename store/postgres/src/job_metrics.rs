@@ -0,0 +1,129 @@
+//! Observability for `graph::util::jobs::Runner` jobs.
+//!
+//! Neither `VacuumDeploymentsJob` nor `RemoveDeploymentsJob` exposed
+//! anything beyond `info!`/`error!` log lines, so operators had no way to
+//! tell how long a vacuum took, how many deployments a sweep removed, or
+//! whether the removal loop keeps hitting its time limit (a sign the
+//! maintenance subsystem is falling behind). This registers per-job
+//! counters and gauges with the crate's Prometheus registry and tracks a
+//! rolling occupancy rate: the fraction of wall-clock time over the last
+//! `OCCUPANCY_WINDOW` that the job actually spent running.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use graph::prelude::{Counter, Gauge, MetricsRegistry, PrometheusError};
+
+/// How far back `occupancy` looks when computing the busy fraction.
+const OCCUPANCY_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+pub struct JobMetrics {
+    runs: Counter,
+    successes: Counter,
+    failures: Counter,
+    last_duration_seconds: Gauge,
+    occupancy_ratio: Gauge,
+    // (when the run ended, how long it took): used to compute `occupancy_ratio`.
+    recent_runs: Mutex<VecDeque<(Instant, Duration)>>,
+}
+
+impl JobMetrics {
+    pub fn new(
+        registry: Arc<dyn MetricsRegistry>,
+        job_name: &str,
+    ) -> Result<Self, PrometheusError> {
+        let mut labels = HashMap::new();
+        labels.insert("job".to_string(), job_name.to_string());
+        Ok(JobMetrics {
+            runs: registry.new_counter(
+                "job_runs_total",
+                "Number of times a maintenance job has run",
+                labels.clone(),
+            )?,
+            successes: registry.new_counter(
+                "job_successes_total",
+                "Number of times a maintenance job finished without error",
+                labels.clone(),
+            )?,
+            failures: registry.new_counter(
+                "job_failures_total",
+                "Number of times a maintenance job finished with an error",
+                labels.clone(),
+            )?,
+            last_duration_seconds: registry.new_gauge(
+                "job_last_duration_seconds",
+                "How long the most recent run of a maintenance job took",
+                labels.clone(),
+            )?,
+            occupancy_ratio: registry.new_gauge(
+                "job_occupancy_ratio",
+                "Fraction of the last hour a maintenance job spent actually running",
+                labels,
+            )?,
+            recent_runs: Mutex::new(VecDeque::new()),
+        })
+    }
+
+    /// Record that a run of this job just finished after `elapsed`, and
+    /// whether it succeeded.
+    pub fn record(&self, elapsed: Duration, success: bool) {
+        self.runs.inc();
+        if success {
+            self.successes.inc();
+        } else {
+            self.failures.inc();
+        }
+        self.last_duration_seconds.set(elapsed.as_secs_f64());
+
+        let now = Instant::now();
+        let mut recent_runs = self.recent_runs.lock().unwrap();
+        recent_runs.push_back((now, elapsed));
+        while recent_runs.front().map_or(false, |(ended, _)| {
+            now.duration_since(*ended) > OCCUPANCY_WINDOW
+        }) {
+            recent_runs.pop_front();
+        }
+        let busy: Duration = recent_runs.iter().map(|(_, d)| *d).sum();
+        self.occupancy_ratio
+            .set(busy.as_secs_f64() / OCCUPANCY_WINDOW.as_secs_f64());
+    }
+}
+
+/// Metrics specific to `RemoveDeploymentsJob::sweep_loop`, beyond the
+/// generic run/success/failure/duration/occupancy tracked by `JobMetrics`.
+pub struct RemovalMetrics {
+    deployments_removed: Gauge,
+    bytes_reclaimed: Gauge,
+    hit_time_limit: Gauge,
+}
+
+impl RemovalMetrics {
+    pub fn new(registry: Arc<dyn MetricsRegistry>) -> Result<Self, PrometheusError> {
+        Ok(RemovalMetrics {
+            deployments_removed: registry.new_gauge(
+                "job_remove_deployments_removed",
+                "Number of deployments removed by the most recent removal run",
+                HashMap::new(),
+            )?,
+            bytes_reclaimed: registry.new_gauge(
+                "job_remove_deployments_bytes_reclaimed",
+                "Bytes reclaimed by the most recent removal run, summed from subgraph_sizes",
+                HashMap::new(),
+            )?,
+            hit_time_limit: registry.new_gauge(
+                "job_remove_deployments_hit_time_limit",
+                "1 if the most recent removal run exited because it hit its time limit, 0 otherwise",
+                HashMap::new(),
+            )?,
+        })
+    }
+
+    /// Record the outcome of one `sweep_loop` run.
+    pub fn record_run(&self, deployments_removed: i64, bytes_reclaimed: i64, hit_time_limit: bool) {
+        self.deployments_removed.set(deployments_removed as f64);
+        self.bytes_reclaimed.set(bytes_reclaimed as f64);
+        self.hit_time_limit
+            .set(if hit_time_limit { 1.0 } else { 0.0 });
+    }
+}
@@ -0,0 +1,177 @@
+//! A Postgres-backed claimable job queue.
+//!
+//! `graph::util::jobs::Runner` fires each registered job on its own timer,
+//! in-process. When several `graph-node` instances share one database, they
+//! all fire at the same time and race to do the same maintenance work. This
+//! module backs maintenance jobs with a `subgraphs.job_queue` table so that
+//! only one worker, anywhere, claims and runs a given job at a time: a
+//! worker claims a row with `update ... where id = (select ... for update
+//! skip locked limit 1)`, refreshes its `heartbeat` while it runs, and
+//! deletes the row when it is done. `reap_stalled` resets jobs whose
+//! heartbeat went stale, presumably because the worker that claimed them
+//! crashed or was killed.
+
+use diesel::sql_types::{Jsonb, Text, Uuid as SqlUuid};
+use diesel::{sql_query, Connection, OptionalExtension, PgConnection, RunQueryDsl};
+use diesel_derive_enum::DbEnum;
+use uuid::Uuid;
+
+use std::time::Duration;
+
+use graph::components::store::StoreError;
+use graph::prelude::serde_json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, DbEnum)]
+#[DieselType = "Job_status"]
+pub enum JobStatus {
+    New,
+    Running,
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::Job_statusMapping;
+
+    subgraphs.job_queue (id) {
+        id -> Uuid,
+        queue -> Varchar,
+        job -> Jsonb,
+        status -> Job_statusMapping,
+        heartbeat -> Timestamptz,
+    }
+}
+
+/// A job that was claimed from `subgraphs.job_queue`, ready to be run.
+pub struct ClaimedJob {
+    pub id: Uuid,
+    pub queue: String,
+    pub job: serde_json::Value,
+}
+
+/// Add a job to `queue`. The job itself is opaque to the queue; callers
+/// give it a shape they can deserialize again when they claim it.
+pub fn enqueue(
+    conn: &PgConnection,
+    queue: &str,
+    job: &serde_json::Value,
+) -> Result<(), StoreError> {
+    use self::job_queue::dsl;
+
+    diesel::insert_into(dsl::job_queue)
+        .values((
+            dsl::queue.eq(queue),
+            dsl::job.eq(job),
+            dsl::status.eq(JobStatus::New),
+        ))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Like [`enqueue`], but a no-op if `queue` already has an outstanding
+/// `new` or `running` job. Callers that tick on a timer use this so a slow
+/// run doesn't pile up a ticket per tick.
+pub fn enqueue_if_absent(
+    conn: &PgConnection,
+    queue: &str,
+    job: &serde_json::Value,
+) -> Result<(), StoreError> {
+    sql_query(
+        "insert into subgraphs.job_queue (queue, job) \
+         select $1, $2 \
+         where not exists ( \
+             select 1 from subgraphs.job_queue \
+              where queue = $1 and status in ('new', 'running') \
+         )",
+    )
+    .bind::<Text, _>(queue)
+    .bind::<Jsonb, _>(job)
+    .execute(conn)?;
+    Ok(())
+}
+
+const CLAIM_QUERY: &str = "
+    update subgraphs.job_queue
+       set status = 'running', heartbeat = now()
+     where id = (
+         select id
+           from subgraphs.job_queue
+          where queue = $1
+            and status = 'new'
+          order by heartbeat
+          for update skip locked
+          limit 1
+     )
+    returning id, job
+";
+
+#[derive(QueryableByName)]
+struct ClaimedRow {
+    #[sql_type = "SqlUuid"]
+    id: Uuid,
+    #[sql_type = "Jsonb"]
+    job: serde_json::Value,
+}
+
+/// Atomically claim the oldest unclaimed job on `queue`, if there is one.
+pub fn claim(conn: &PgConnection, queue: &str) -> Result<Option<ClaimedJob>, StoreError> {
+    let claimed = sql_query(CLAIM_QUERY)
+        .bind::<Text, _>(queue)
+        .get_result::<ClaimedRow>(conn)
+        .optional()?;
+    Ok(claimed.map(|row| ClaimedJob {
+        id: row.id,
+        queue: queue.to_string(),
+        job: row.job,
+    }))
+}
+
+/// Refresh the heartbeat of a claimed job so the reaper leaves it alone.
+/// Long-running jobs like `sweep_loop` should call this periodically.
+pub fn heartbeat(conn: &PgConnection, id: Uuid) -> Result<(), StoreError> {
+    use self::job_queue::dsl;
+
+    diesel::update(dsl::job_queue.filter(dsl::id.eq(id)))
+        .set(dsl::heartbeat.eq(diesel::dsl::now))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// Remove a job from the queue once it has finished running.
+pub fn complete(conn: &PgConnection, id: Uuid) -> Result<(), StoreError> {
+    use self::job_queue::dsl;
+
+    diesel::delete(dsl::job_queue.filter(dsl::id.eq(id))).execute(conn)?;
+    Ok(())
+}
+
+/// Put a claimed job back up for grabs without waiting for the reaper's
+/// `STALL_TIMEOUT`. Callers that voluntarily give up a job they haven't
+/// finished - e.g. because they hit their own time limit - should use this
+/// instead of `complete`, so the remaining work is picked up on the next
+/// tick rather than sitting idle until the heartbeat goes stale.
+pub fn release(conn: &PgConnection, id: Uuid) -> Result<(), StoreError> {
+    use self::job_queue::dsl;
+
+    diesel::update(dsl::job_queue.filter(dsl::id.eq(id)))
+        .set(dsl::status.eq(JobStatus::New))
+        .execute(conn)?;
+    Ok(())
+}
+
+const REAP_QUERY: &str = "
+    update subgraphs.job_queue
+       set status = 'new'
+     where status = 'running'
+       and heartbeat < now() - $1::interval
+";
+
+/// Reset any `running` job whose heartbeat is older than `timeout` back to
+/// `new`, so another worker picks it up. The job that held it is assumed
+/// to have died or been killed without cleaning up after itself.
+pub fn reap_stalled(conn: &PgConnection, timeout: Duration) -> Result<usize, StoreError> {
+    let interval = format!("{} seconds", timeout.as_secs());
+    let reset = sql_query(REAP_QUERY)
+        .bind::<Text, _>(interval)
+        .execute(conn)?;
+    Ok(reset)
+}
@@ -5,22 +5,171 @@ use diesel::prelude::{
     OptionalExtension, QueryDsl, RunQueryDsl,
 };
 use diesel::{connection::SimpleConnection, sql_query, sql_types::Text, Connection, PgConnection};
+use uuid::Uuid;
 
 use std::time::{Duration, Instant};
 use std::{collections::HashSet, sync::Arc};
 
-use graph::prelude::{bigdecimal::ToPrimitive, error, info, Logger, SubgraphDeploymentId};
-use graph::util::jobs::{Job, Runner};
+use graph::prelude::{
+    bigdecimal::ToPrimitive, error, info, serde_json, Logger, MetricsRegistry, SubgraphDeploymentId,
+};
+use graph::util::jobs::{Job, Runner, Schedule};
 use graph::{components::store::StoreError, prelude::BigDecimal};
 
 use crate::entities::{find_schema, Schema as DeploymentSchema};
+use crate::job_metrics::{JobMetrics, RemovalMetrics};
+use crate::job_queue;
+use crate::lifecycle;
 use crate::Store;
 
-pub fn register(runner: &mut Runner, store: Arc<Store>) {
+/// How long a claimed job can go without a heartbeat before the reaper
+/// assumes its worker died and puts it back up for grabs.
+const STALL_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+// Not generated from the GraphQL schema like the tables in `crate::metadata`
+// and `crate::entities`: this is a bookkeeping table for the removal job
+// itself, so it is declared here next to its only user.
+table! {
+    subgraphs.deployment_tombstones (deployment) {
+        deployment -> Varchar,
+        schema_name -> Varchar,
+        marked_at -> Timestamptz,
+        lifecycle_rule_id -> Nullable<Int4>,
+        grace_period_days -> Nullable<Int4>,
+    }
+}
+
+/// A deployment that has been continuously unassigned and not a
+/// current/pending version for at least this long is eligible for the
+/// sweep phase to actually drop its schema.
+const REMOVAL_GRACE_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub fn register(runner: &mut Runner, store: Arc<Store>, registry: Arc<dyn MetricsRegistry>) {
+    // Vacuuming is cheap and the table is write-heavy, so keep doing it
+    // often; there is no need to run it on a fixed one-minute cadence any
+    // more now that overlapping runs across nodes are deduplicated by the
+    // job queue.
+    runner.register(
+        Box::new(ClaimingJob::new(
+            "vacuum_deployments",
+            store.clone(),
+            Box::new(VacuumDeploymentsJob::new(store.clone())),
+            registry.clone(),
+        )),
+        Schedule::Cron("*/5 * * * *".to_string()),
+    );
+
+    let remove = Arc::new(RemoveDeploymentsJob::new(store, registry));
+    // Scanning every deployment for removal eligibility is comparatively
+    // expensive, so the mark phase only runs once a night.
     runner.register(
-        Box::new(VacuumDeploymentsJob::new(store)),
-        Duration::from_secs(60),
+        Box::new(MarkRemovableDeploymentsJob(remove.clone())),
+        Schedule::Cron("0 3 * * *".to_string()),
     );
+    // The sweep phase only touches deployments that are already
+    // tombstoned, so it is cheap to check often. Running it on the same
+    // cadence as vacuum means a sweep that hits `TIME_LIMIT` resumes
+    // within minutes instead of sitting on its queue, unworked, until the
+    // next night's mark phase ticket comes around.
+    runner.register(
+        Box::new(SweepTombstonedDeploymentsJob(remove)),
+        Schedule::Cron("*/5 * * * *".to_string()),
+    );
+}
+
+/// A job whose `run` reports whether it succeeded, so that `ClaimingJob`
+/// can feed the outcome into `JobMetrics` instead of only logging it.
+trait CheckedJob: Send + Sync {
+    fn name(&self) -> &str;
+    fn run_checked(&self, logger: &Logger) -> Result<(), StoreError>;
+}
+
+/// Wraps a job so that each tick enqueues a ticket into
+/// `subgraphs.job_queue` and only actually runs the job if this process is
+/// the one that claims it. Several `graph-node` instances can share a
+/// database and all run this same code on their own timer; without this,
+/// they would all run the wrapped job at once instead of exactly one of
+/// them doing the work. Also records run count, success/failure count,
+/// last run duration, and occupancy in `JobMetrics`.
+struct ClaimingJob {
+    queue: &'static str,
+    store: Arc<Store>,
+    inner: Box<dyn CheckedJob>,
+    metrics: JobMetrics,
+}
+
+impl ClaimingJob {
+    fn new(
+        queue: &'static str,
+        store: Arc<Store>,
+        inner: Box<dyn CheckedJob>,
+        registry: Arc<dyn MetricsRegistry>,
+    ) -> Self {
+        let metrics = JobMetrics::new(registry, inner.name())
+            .expect("Failed to register job metrics with the Prometheus registry");
+        ClaimingJob {
+            queue,
+            store,
+            inner,
+            metrics,
+        }
+    }
+}
+
+impl Job for ClaimingJob {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn run(&self, logger: &Logger) {
+        let conn = match self.store.get_conn() {
+            Ok(conn) => conn,
+            Err(e) => {
+                error!(logger, "Could not claim job `{}`: {}", self.name(), e);
+                return;
+            }
+        };
+
+        if let Err(e) = job_queue::reap_stalled(&conn, STALL_TIMEOUT) {
+            error!(logger, "Failed to reap stalled jobs: {}", e);
+        }
+
+        if let Err(e) = job_queue::enqueue_if_absent(&conn, self.queue, &serde_json::Value::Null) {
+            error!(logger, "Failed to enqueue job `{}`: {}", self.name(), e);
+            return;
+        }
+
+        let claimed = match job_queue::claim(&conn, self.queue) {
+            Ok(claimed) => claimed,
+            Err(e) => {
+                error!(logger, "Failed to claim job `{}`: {}", self.name(), e);
+                return;
+            }
+        };
+
+        // Another node already claimed the outstanding ticket for this
+        // queue; nothing to do this tick.
+        let claimed = match claimed {
+            Some(claimed) => claimed,
+            None => return,
+        };
+
+        let start = Instant::now();
+        let result = self.inner.run_checked(logger);
+        if let Err(ref e) = result {
+            error!(logger, "Job `{}` failed: {}", self.name(), e);
+        }
+        self.metrics.record(start.elapsed(), result.is_ok());
+
+        if let Err(e) = job_queue::complete(&conn, claimed.id) {
+            error!(
+                logger,
+                "Failed to remove completed job `{}` from the queue: {}",
+                self.name(),
+                e
+            );
+        }
+    }
 }
 
 /// A job that vacuums `subgraphs.subgraph_deployment`. With a large number
@@ -43,29 +192,48 @@ impl VacuumDeploymentsJob {
     }
 }
 
-impl Job for VacuumDeploymentsJob {
+impl CheckedJob for VacuumDeploymentsJob {
     fn name(&self) -> &str {
         "Vacuum subgraphs.subgraph_deployment"
     }
 
-    fn run(&self, logger: &Logger) {
-        if let Err(e) = self.vacuum() {
-            error!(
-                logger,
-                "Vacuum of subgraphs.subgraph_deployment failed: {}", e
-            );
-        }
+    fn run_checked(&self, _logger: &Logger) -> Result<(), StoreError> {
+        self.vacuum()
     }
 }
 
 struct RemoveDeploymentsJob {
     store: Arc<Store>,
+    mark_metrics: JobMetrics,
+    sweep_metrics: JobMetrics,
+    removal_metrics: RemovalMetrics,
 }
 
 impl RemoveDeploymentsJob {
-    fn next_removable_deployment(
-        conn: &PgConnection,
-    ) -> Result<Option<DeploymentSchema>, StoreError> {
+    fn new(store: Arc<Store>, registry: Arc<dyn MetricsRegistry>) -> RemoveDeploymentsJob {
+        // Each phase has its own schedule (and can hit its own time
+        // limit), so each gets its own `JobMetrics`, named after the job
+        // it is actually registered with the `Runner` as.
+        let mark_metrics = JobMetrics::new(registry.clone(), "Mark removable deployments")
+            .expect("Failed to register job metrics with the Prometheus registry");
+        let sweep_metrics = JobMetrics::new(registry.clone(), "Sweep tombstoned deployments")
+            .expect("Failed to register job metrics with the Prometheus registry");
+        let removal_metrics = RemovalMetrics::new(registry)
+            .expect("Failed to register removal metrics with the Prometheus registry");
+        RemoveDeploymentsJob {
+            store,
+            mark_metrics,
+            sweep_metrics,
+            removal_metrics,
+        }
+    }
+
+    // The ids of deployments that are, right now, unassigned, not the
+    // current or pending version of any subgraph, and relational. This is
+    // everything eligible for removal, not just the removal-ready ones:
+    // callers decide what to do with "eligible" vs. "has been eligible for
+    // a full grace period" (see `deployment_tombstones`).
+    fn eligible_deployment_ids(conn: &PgConnection) -> Result<Vec<String>, StoreError> {
         use crate::entities::public::deployment_schemas as ds;
         use crate::entities::public::DeploymentSchemaVersion as DSV;
         use crate::metadata::subgraph as s;
@@ -73,7 +241,7 @@ impl RemoveDeploymentsJob {
         use crate::metadata::subgraph_deployment_assignment as a;
         use crate::metadata::subgraph_version as v;
 
-        let sid = d::table
+        d::table
             .inner_join(ds::table.on(d::id.eq(ds::subgraph)))
             .select(d::id)
             // The deployment must use relational storage
@@ -93,14 +261,161 @@ impl RemoveDeploymentsJob {
                             .or(s::pending_version.eq(v::id.nullable())),
                     ),
             )))
-            .first::<String>(conn)
+            .load::<String>(conn)
+            .map_err(StoreError::from)
+    }
+
+    // The subgraph names that have ever pointed a version at `sid`. A
+    // deployment can outlive the subgraphs that used it, so this is the
+    // only way to know which lifecycle rules apply to it once it is no
+    // longer anyone's current or pending version.
+    fn deployment_subgraph_names(
+        conn: &PgConnection,
+        sid: &str,
+    ) -> Result<Vec<String>, StoreError> {
+        use crate::metadata::subgraph as s;
+        use crate::metadata::subgraph_version as v;
+
+        s::table
+            .inner_join(v::table.on(s::id.eq(v::subgraph)))
+            .select(s::name)
+            .filter(v::deployment.eq(sid))
+            .distinct()
+            .load::<String>(conn)
+            .map_err(StoreError::from)
+    }
+
+    // The `total_bytes` of a deployment as last recorded in
+    // `subgraph_sizes`, if any.
+    fn deployment_total_bytes(conn: &PgConnection, sid: &str) -> Result<Option<i64>, StoreError> {
+        use crate::metadata::subgraph_sizes as sz;
+
+        sz::table
+            .select(sz::total_bytes)
+            .filter(sz::subgraph.eq(sid))
+            .get_result::<BigDecimal>(conn)
+            .optional()
+            .map(|bytes| bytes.and_then(|b| b.to_i64()))
+            .map_err(StoreError::from)
+    }
+
+    // Mark phase: tombstone every deployment that is eligible for removal
+    // right now and not exempted by a lifecycle rule, and un-tombstone any
+    // deployment that got reassigned, became a current/pending version
+    // again, or is now covered by a `never_remove` rule. A deployment must
+    // stay continuously tombstoned for its grace period - the default, or
+    // a `remove_after_days` override from a matching rule - before
+    // `next_sweepable_tombstone` will consider it for the sweep phase, so
+    // a deployment that flickers in and out of eligibility (e.g. during a
+    // reassignment) never gets dropped.
+    fn mark_removable_deployments(conn: &PgConnection, claimed_id: Uuid) -> Result<(), StoreError> {
+        use self::deployment_tombstones::dsl as tomb;
+
+        let eligible = Self::eligible_deployment_ids(conn)?;
+        let rules = lifecycle::load_rules(conn)?;
+        let mut tombstoned = Vec::with_capacity(eligible.len());
+
+        for sid in &eligible {
+            // This loop does several queries per eligible deployment, and
+            // an installation with many tenants can have enough of them
+            // that a full pass runs past `STALL_TIMEOUT`. Heartbeat as we
+            // go so the reaper doesn't hand this run to another node out
+            // from under us.
+            job_queue::heartbeat(conn, claimed_id)?;
+
+            let names = Self::deployment_subgraph_names(conn, sid)?;
+            let rule = lifecycle::matching_rule(&rules, &names);
+            let (rule_id, grace_period_days) = match rule.map(|r| (r.id, r.action())) {
+                Some((_, lifecycle::Action::NeverRemove)) => continue,
+                Some((_, lifecycle::Action::RemoveIfLargerThanBytes(threshold))) => {
+                    let size = Self::deployment_total_bytes(conn, sid)?;
+                    if size.unwrap_or(0) <= threshold {
+                        continue;
+                    }
+                    (rule.map(|r| r.id), None)
+                }
+                Some((id, lifecycle::Action::RemoveAfterDays(days))) => (Some(id), Some(days)),
+                // A rule matched but its action was malformed (unrecognized,
+                // or missing the parameter its action requires): fail safe
+                // and treat it exactly like no rule matching.
+                Some((_, lifecycle::Action::Unrecognized)) => (None, None),
+                None => (None, None),
+            };
+
+            let deployment = SubgraphDeploymentId::new(sid.clone())
+                .expect("Deployment ids in the database are valid");
+            if let Some(schema) = find_schema(conn, &deployment)? {
+                // `marked_at` is intentionally left alone on conflict - it
+                // is the start of the grace period and must stay
+                // append-only - but `lifecycle_rule_id`/`grace_period_days`
+                // are refreshed so that an operator adding or editing a
+                // rule takes effect on deployments that are already
+                // tombstoned, not just newly-tombstoned ones.
+                diesel::insert_into(tomb::deployment_tombstones)
+                    .values((
+                        tomb::deployment.eq(sid),
+                        tomb::schema_name.eq(&schema.name),
+                        tomb::lifecycle_rule_id.eq(rule_id),
+                        tomb::grace_period_days.eq(grace_period_days),
+                    ))
+                    .on_conflict(tomb::deployment)
+                    .do_update()
+                    .set((
+                        tomb::lifecycle_rule_id.eq(rule_id),
+                        tomb::grace_period_days.eq(grace_period_days),
+                    ))
+                    .execute(conn)?;
+                tombstoned.push(sid.clone());
+            }
+        }
+
+        diesel::delete(tomb::deployment_tombstones.filter(tomb::deployment.ne_all(&tombstoned)))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Sweep phase: find a tombstoned deployment whose grace period - a
+    // rule's `remove_after_days` if it has one, `default_grace` otherwise
+    // - has expired. `mark_removable_deployments` only deletes the
+    // tombstones of deployments that stopped being eligible when it runs,
+    // so the query also skips any tombstoned deployment that has since
+    // been assigned or made a current/pending version again; any row we
+    // see here is still removable.
+    fn next_sweepable_tombstone(
+        conn: &PgConnection,
+        default_grace: Duration,
+    ) -> Result<Option<DeploymentSchema>, StoreError> {
+        #[derive(QueryableByName)]
+        struct SweepableRow {
+            #[sql_type = "Text"]
+            deployment: String,
+        }
+
+        const QUERY: &str = "
+            select t.deployment
+              from subgraphs.deployment_tombstones t
+             where t.marked_at < now() - (coalesce(t.grace_period_days || ' days', $1 || ' seconds'))::interval
+               and not exists (select 1 from subgraphs.subgraph_deployment_assignment a
+                                where a.id = t.deployment)
+               and not exists (select 1
+                                 from subgraphs.subgraph s
+                                 join subgraphs.subgraph_version v on s.id = v.subgraph
+                                where v.deployment = t.deployment
+                                  and (s.current_version = v.id or s.pending_version = v.id))
+             limit 1
+        ";
+
+        let row = sql_query(QUERY)
+            .bind::<Text, _>(default_grace.as_secs().to_string())
+            .get_result::<SweepableRow>(conn)
             .optional()?;
-        if let Some(sid) = sid {
-            let deployment =
-                SubgraphDeploymentId::new(sid).expect("Deployment ids in the database are valid");
-            find_schema(conn, &deployment)
-        } else {
-            Ok(None)
+        match row {
+            Some(row) => {
+                let deployment = SubgraphDeploymentId::new(row.deployment)
+                    .expect("Deployment ids in the database are valid");
+                find_schema(conn, &deployment)
+            }
+            None => Ok(None),
         }
     }
 
@@ -140,7 +455,8 @@ impl RemoveDeploymentsJob {
         deployment: &DeploymentSchema,
         created_at: Option<i32>,
         subgraphs: String,
-    ) -> Result<Option<i32>, StoreError> {
+        triggered_by_rule: Option<i32>,
+    ) -> Result<(Option<i32>, Option<i64>), StoreError> {
         use crate::metadata::removed_deployments as rd;
         use crate::metadata::subgraph_deployment as d;
         use crate::metadata::subgraph_sizes as sz;
@@ -188,17 +504,26 @@ impl RemoveDeploymentsJob {
                 rd::index_bytes.eq(index_bytes),
                 rd::toast_bytes.eq(toast_bytes),
                 rd::table_bytes.eq(table_bytes),
+                rd::triggered_by_rule.eq(triggered_by_rule),
             ))
             .execute(conn)?;
-        Ok(entity_count)
+        Ok((entity_count, total_bytes.and_then(|b| b.to_i64())))
     }
 
     fn remove_deployment(
         conn: &PgConnection,
         deployment: &DeploymentSchema,
-    ) -> Result<(i32, i32), StoreError> {
+    ) -> Result<(i32, i32, i64), StoreError> {
+        use self::deployment_tombstones::dsl as tomb;
         use crate::entities::public::deployment_schemas as ds;
 
+        let triggered_by_rule = tomb::deployment_tombstones
+            .select(tomb::lifecycle_rule_id)
+            .filter(tomb::deployment.eq(&deployment.subgraph))
+            .get_result::<Option<i32>>(conn)
+            .optional()?
+            .flatten();
+
         // The query in this file was generated by running 'make'
         // in the 'sql/' subdirectory
         // See also: ed42d219c6704a4aab57ce1ea66698e7
@@ -228,45 +553,146 @@ impl RemoveDeploymentsJob {
         // Remove any subgraph versions referring to this deployment
         let (created_at, subgraphs) = Self::remove_versions(conn, deployment)?;
 
-        let entity_count = Self::record_removal(conn, deployment, created_at, subgraphs)?;
+        let (entity_count, bytes_reclaimed) =
+            Self::record_removal(conn, deployment, created_at, subgraphs, triggered_by_rule)?;
+
+        // The deployment is gone, so its tombstone no longer means anything
+        diesel::delete(
+            tomb::deployment_tombstones.filter(tomb::deployment.eq(&deployment.subgraph)),
+        )
+        .execute(conn)?;
+
+        Ok((
+            entity_count.unwrap_or(0),
+            metadata_count,
+            bytes_reclaimed.unwrap_or(0),
+        ))
+    }
+
+    // Mark phase: tombstone everything that looks removable right now, and
+    // drop tombstones for anything that no longer does. Shares a queue
+    // ticket so only one node does this scan at a time.
+    fn mark_loop(&self, _logger: &Logger) -> Result<(), StoreError> {
+        const QUEUE: &str = "remove_deployments_mark";
+
+        let conn = self.store.get_conn()?;
 
-        Ok((entity_count.unwrap_or(0), metadata_count))
+        job_queue::reap_stalled(&conn, STALL_TIMEOUT)?;
+        job_queue::enqueue_if_absent(&conn, QUEUE, &serde_json::Value::Null)?;
+        let claimed = match job_queue::claim(&conn, QUEUE)? {
+            Some(claimed) => claimed,
+            // Another node already claimed this run; nothing to do.
+            None => return Ok(()),
+        };
+
+        Self::mark_removable_deployments(&conn, claimed.id)?;
+        job_queue::complete(&conn, claimed.id)?;
+        Ok(())
     }
 
-    fn removal_loop(&self, logger: &Logger) -> Result<(), StoreError> {
+    // Sweep phase: only deployments that have stayed tombstoned for a full
+    // grace period are actually destroyed. Runs on its own queue ticket,
+    // separate from the mark phase, so that it can be retried on a much
+    // shorter cadence: a sweep that runs out of time releases its ticket
+    // instead of completing it, so the next tick resumes where this one
+    // left off rather than waiting for the next scheduled run.
+    fn sweep_loop(&self, logger: &Logger) -> Result<RemovalRunSummary, StoreError> {
         // To avoid holding up overall job execution, do this for no
-        // more than 5 minutes
+        // more than 5 minutes per tick.
         const TIME_LIMIT: Duration = Duration::from_secs(300);
 
+        const QUEUE: &str = "remove_deployments_sweep";
+
         let conn = self.store.get_conn()?;
         let start = Instant::now();
+        let mut summary = RemovalRunSummary::default();
+
+        job_queue::reap_stalled(&conn, STALL_TIMEOUT)?;
+        job_queue::enqueue_if_absent(&conn, QUEUE, &serde_json::Value::Null)?;
+        let claimed = match job_queue::claim(&conn, QUEUE)? {
+            Some(claimed) => claimed,
+            // Another node already claimed this run; nothing to do.
+            None => return Ok(summary),
+        };
 
-        while let Some(deployment) = Self::next_removable_deployment(&conn)? {
+        while let Some(deployment) = Self::next_sweepable_tombstone(&conn, REMOVAL_GRACE_PERIOD)? {
             info!(logger, "Remove unused deployment"; "deployment" => &deployment.subgraph);
-            let (entity_count, metadata_count) =
+            let (entity_count, metadata_count, bytes_reclaimed) =
                 conn.transaction(|| Self::remove_deployment(&conn, &deployment))?;
             info!(logger, "Removed unused deployment";
                     "deployment" => &deployment.subgraph,
                     "schema" => &deployment.name,
                     "entity_count" => entity_count,
                     "metadata_count" => metadata_count);
+            summary.deployments_removed += 1;
+            summary.bytes_reclaimed += bytes_reclaimed;
+
+            // Let the reaper know we are still making progress so it
+            // doesn't hand this run to another node out from under us.
+            job_queue::heartbeat(&conn, claimed.id)?;
 
             if start.elapsed() > TIME_LIMIT {
-                return Ok(());
+                summary.hit_time_limit = true;
+                // There is more work left to do. Put the ticket back up
+                // for grabs instead of completing it, so the next tick -
+                // minutes away, not a full day - picks up right where
+                // this one left off.
+                job_queue::release(&conn, claimed.id)?;
+                return Ok(summary);
             }
         }
-        Ok(())
+        job_queue::complete(&conn, claimed.id)?;
+        Ok(summary)
+    }
+}
+
+/// What happened during one `sweep_loop` run, fed into `RemovalMetrics`.
+#[derive(Default)]
+struct RemovalRunSummary {
+    deployments_removed: i64,
+    bytes_reclaimed: i64,
+    hit_time_limit: bool,
+}
+
+/// Runs `RemoveDeploymentsJob`'s mark phase on its own (nightly) schedule.
+struct MarkRemovableDeploymentsJob(Arc<RemoveDeploymentsJob>);
+
+impl Job for MarkRemovableDeploymentsJob {
+    fn name(&self) -> &str {
+        "Mark removable deployments"
+    }
+
+    fn run(&self, logger: &Logger) {
+        let start = Instant::now();
+        let result = self.0.mark_loop(logger);
+        if let Err(ref e) = result {
+            error!(logger, "Job `{}` failed: {}", self.name(), e);
+        }
+        self.0.mark_metrics.record(start.elapsed(), result.is_ok());
     }
 }
 
-impl Job for RemoveDeploymentsJob {
+/// Runs `RemoveDeploymentsJob`'s sweep phase on its own (frequent) schedule.
+struct SweepTombstonedDeploymentsJob(Arc<RemoveDeploymentsJob>);
+
+impl Job for SweepTombstonedDeploymentsJob {
     fn name(&self) -> &str {
-        "Remove unused deployments"
+        "Sweep tombstoned deployments"
     }
 
     fn run(&self, logger: &Logger) {
-        if let Err(e) = self.removal_loop(logger) {
+        let start = Instant::now();
+        let result = self.0.sweep_loop(logger);
+        if let Err(ref e) = result {
             error!(logger, "Job `{}` failed: {}", self.name(), e);
         }
+        self.0.sweep_metrics.record(start.elapsed(), result.is_ok());
+        if let Ok(summary) = result {
+            self.0.removal_metrics.record_run(
+                summary.deployments_removed,
+                summary.bytes_reclaimed,
+                summary.hit_time_limit,
+            );
+        }
     }
 }
@@ -0,0 +1,106 @@
+//! Per-subgraph deployment retention rules.
+//!
+//! `removal_loop` used to apply one hard-coded global policy: any
+//! unassigned, non-current, non-pending relational deployment was eligible
+//! for removal. Operators hosting many tenants need finer control - keep
+//! some namespaces' data around longer, exempt others from automatic
+//! removal entirely, or only sweep deployments above a size threshold.
+//! Rules live in `subgraphs.deployment_lifecycle_rules` and are matched
+//! against a deployment's subgraph name, modeled loosely on object-store
+//! lifecycle configuration.
+
+use diesel::prelude::*;
+use diesel::PgConnection;
+use regex::Regex;
+
+use graph::components::store::StoreError;
+
+table! {
+    subgraphs.deployment_lifecycle_rules (id) {
+        id -> Int4,
+        name_pattern -> Varchar,
+        is_regex -> Bool,
+        priority -> Int4,
+        action -> Varchar,
+        remove_after_days -> Nullable<Int4>,
+        remove_if_larger_than_bytes -> Nullable<Int8>,
+    }
+}
+
+#[derive(Debug, Clone, Queryable)]
+pub struct Rule {
+    pub id: i32,
+    pub name_pattern: String,
+    pub is_regex: bool,
+    pub priority: i32,
+    pub action: String,
+    pub remove_after_days: Option<i32>,
+    pub remove_if_larger_than_bytes: Option<i64>,
+}
+
+/// What a matching rule says to do with a deployment that would otherwise
+/// be eligible for removal.
+pub enum Action {
+    /// This subgraph's deployments must never be auto-removed.
+    NeverRemove,
+    /// Override the default grace period with this many days.
+    RemoveAfterDays(i32),
+    /// Only remove the deployment once it is larger than this many bytes.
+    RemoveIfLargerThanBytes(i64),
+    /// The rule's `action` column wasn't one we recognize, or was missing
+    /// the parameter its action requires (e.g. `remove_after_days` with no
+    /// `remove_after_days` value). Callers should treat this exactly like
+    /// no rule matching at all, so a malformed rule fails safe to the
+    /// default grace period instead of causing immediate removal.
+    Unrecognized,
+}
+
+impl Rule {
+    fn matches(&self, subgraph_name: &str) -> bool {
+        if self.is_regex {
+            Regex::new(&self.name_pattern)
+                .map(|re| re.is_match(subgraph_name))
+                .unwrap_or(false)
+        } else {
+            subgraph_name.starts_with(self.name_pattern.as_str())
+        }
+    }
+
+    pub fn action(&self) -> Action {
+        match self.action.as_str() {
+            "never_remove" => Action::NeverRemove,
+            "remove_after_days" => match self.remove_after_days {
+                Some(days) => Action::RemoveAfterDays(days),
+                None => Action::Unrecognized,
+            },
+            "remove_if_larger_than_bytes" => match self.remove_if_larger_than_bytes {
+                Some(bytes) => Action::RemoveIfLargerThanBytes(bytes),
+                None => Action::Unrecognized,
+            },
+            // An unrecognized action, or a recognized one missing the
+            // parameter it requires, fails safe: see `Action::Unrecognized`.
+            _ => Action::Unrecognized,
+        }
+    }
+}
+
+/// Load all configured rules, highest priority first, so that the first
+/// match found for a subgraph name is the one that should apply. `priority`
+/// defaults to 0 and is commonly left unset, so rules are also ordered by
+/// `id` as a tiebreaker - otherwise which of two equal-priority matching
+/// rules wins would depend on Postgres's unspecified row order and could
+/// flip from one mark phase to the next with no configuration change.
+pub fn load_rules(conn: &PgConnection) -> Result<Vec<Rule>, StoreError> {
+    use self::deployment_lifecycle_rules::dsl::*;
+
+    Ok(deployment_lifecycle_rules
+        .order((priority.desc(), id.asc()))
+        .load::<Rule>(conn)?)
+}
+
+/// The highest-priority rule matching any of `subgraph_names`, if any.
+pub fn matching_rule<'a>(rules: &'a [Rule], subgraph_names: &[String]) -> Option<&'a Rule> {
+    rules
+        .iter()
+        .find(|rule| subgraph_names.iter().any(|name| rule.matches(name)))
+}